@@ -3,10 +3,14 @@
 //! are defined to represent various ASN.1 data types, and renamed to use
 //! ASN.1's terminology.
 
+mod duration;
+mod enumerated;
 mod instance;
 mod oid;
 mod open;
 mod prefix;
+mod real;
+mod strings;
 
 pub use rasn_derive::AsnType;
 
@@ -15,19 +19,20 @@ pub use bytes::Bytes as OctetString;
 pub use num_bigint::BigInt as Integer;
 
 pub use super::tag::{Class, Tag};
+pub use duration::Duration;
+pub use enumerated::{Enumerable, Enumerated};
 pub use instance::InstanceOf;
 pub use oid::{ConstOid, ObjectIdentifier, Oid};
 pub use open::Open;
 pub use prefix::{Explicit, Implicit};
+pub use real::Real;
+pub use strings::{
+    GeneralString, GraphicString, IA5String, InvalidRestrictedString, NumericString,
+    ObjectDescriptor, PrintableString, T61String, TeletexString, VideotexString, VisibleString,
+};
 
 ///  Alias for `bitvec::BitVec` mapped to ASN.1'a `BIT STRING`.
 pub type BitString = bitvec::vec::BitVec<bitvec::order::Msb0, u8>;
-///  `IA5String` string alias that matches BER's encoding rules.
-pub type IA5String = Implicit<{ Tag::IA5_STRING }, Utf8String>;
-///  `PrintableString` string alias that matches BER's encoding rules.
-pub type PrintableString = Implicit<{ Tag::PRINTABLE_STRING }, Utf8String>;
-///  `VisibleString` string alias that matches BER's encoding rules.
-pub type VisibleString = Implicit<{ Tag::VISIBLE_STRING }, Utf8String>;
 ///  `String` alias that matches `BmpString` BER's encoding rules.
 pub type BmpString = Implicit<{ Tag::BMP_STRING }, Utf8String>;
 ///  Alias to `Vec<T>`.
@@ -38,6 +43,50 @@ pub type UniversalString = Implicit<{ Tag::UNIVERSAL_STRING }, Utf8String>;
 pub type UtcTime = chrono::DateTime<chrono::Utc>;
 ///  Alias for `chrono::DateTime<FixedOffset>`.
 pub type GeneralizedTime = chrono::DateTime<chrono::FixedOffset>;
+///  Alias for `chrono::NaiveDate`, mapped to ASN.1's `DATE`.
+pub type Date = chrono::NaiveDate;
+///  Alias for `chrono::NaiveTime`, mapped to ASN.1's `TIME-OF-DAY`.
+pub type TimeOfDay = chrono::NaiveTime;
+///  Alias for `chrono::NaiveDateTime`, mapped to ASN.1's `DATE-TIME`.
+pub type DateTime = chrono::NaiveDateTime;
+
+/// Implements the codec for a `chrono` useful-time type as its ISO 8601
+/// textual representation carried in the contents octets.
+macro_rules! iso8601_codec {
+    ($($ty:ty: $format:literal),+ $(,)?) => {
+        $(
+            impl crate::enc::Encode for $ty {
+                fn encode_with_tag<E: crate::enc::Encoder>(
+                    &self,
+                    encoder: &mut E,
+                    tag: Tag,
+                ) -> Result<(), E::Error> {
+                    use alloc::string::ToString;
+                    let formatted = self.format($format).to_string();
+                    encoder.encode_octet_string(tag, formatted.as_bytes()).map(drop)
+                }
+            }
+
+            impl crate::de::Decode for $ty {
+                fn decode_with_tag<D: crate::de::Decoder>(
+                    decoder: &mut D,
+                    tag: Tag,
+                ) -> Result<Self, D::Error> {
+                    use crate::de::Error;
+                    let bytes = decoder.decode_octet_string(tag)?;
+                    let text = core::str::from_utf8(&bytes).map_err(D::Error::custom)?;
+                    <$ty>::parse_from_str(text, $format).map_err(D::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+iso8601_codec! {
+    Date: "%Y-%m-%d",
+    TimeOfDay: "%H:%M:%S",
+    DateTime: "%Y-%m-%dT%H:%M:%S",
+}
 
 /// A trait representing any type that can represented in ASN.1.
 pub trait AsnType {
@@ -78,7 +127,19 @@ asn_type! {
     u64: INTEGER,
     u128: INTEGER,
     usize: INTEGER,
+    f32: REAL,
+    f64: REAL,
     Integer: INTEGER,
+    Real: REAL,
+    IA5String: IA5_STRING,
+    PrintableString: PRINTABLE_STRING,
+    VisibleString: VISIBLE_STRING,
+    NumericString: NUMERIC_STRING,
+    TeletexString: TELETEX_STRING,
+    VideotexString: VIDEOTEX_STRING,
+    GraphicString: GRAPHIC_STRING,
+    GeneralString: GENERAL_STRING,
+    ObjectDescriptor: OBJECT_DESCRIPTOR,
     OctetString: OCTET_STRING,
     ObjectIdentifier: OBJECT_IDENTIFIER,
     Oid: OBJECT_IDENTIFIER,
@@ -87,6 +148,9 @@ asn_type! {
     Utf8String: UTF8_STRING,
     UtcTime: UTC_TIME,
     GeneralizedTime: GENERALIZED_TIME,
+    Date: DATE,
+    TimeOfDay: TIME_OF_DAY,
+    DateTime: DATE_TIME,
     (): NULL,
     &'_ str: UTF8_STRING
 
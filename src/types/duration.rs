@@ -0,0 +1,218 @@
+//! # Duration
+//! The ASN.1 `DURATION` type, carrying an ISO 8601 duration of the form
+//! `PnYnMnDTnHnMnS`.
+
+use super::{AsnType, Tag};
+
+/// An ASN.1 `DURATION` value.
+///
+/// Each component counts a calendar unit; they are not normalised against one
+/// another (e.g. 90 minutes is *not* folded into 1 hour 30 minutes), so each
+/// component keeps the value it was parsed with. The textual form is not kept:
+/// zero components are omitted when formatting (`P0Y1M` formats as `P1M`), and
+/// an all-zero value formats as `PT0S`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Duration {
+    /// Number of years.
+    pub years: u64,
+    /// Number of months.
+    pub months: u64,
+    /// Number of days.
+    pub days: u64,
+    /// Number of hours.
+    pub hours: u64,
+    /// Number of minutes.
+    pub minutes: u64,
+    /// Number of seconds.
+    pub seconds: u64,
+}
+
+impl AsnType for Duration {
+    const TAG: Tag = Tag::DURATION;
+}
+
+impl core::fmt::Display for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self == &Duration::default() {
+            // `P` alone is not a valid duration; emit the canonical zero.
+            return f.write_str("PT0S");
+        }
+        f.write_str("P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 {
+            f.write_str("T")?;
+            if self.hours != 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+            if self.minutes != 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+            if self.seconds != 0 {
+                write!(f, "{}S", self.seconds)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for Duration {
+    type Err = InvalidDuration;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rest = input.strip_prefix('P').ok_or(InvalidDuration)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+
+        // Parse each section by scanning digit runs terminated by a unit
+        // designator, validating the designators appear in canonical order.
+        let mut duration = Duration::default();
+        let mut matched = parse_section(date, &[('Y', 0), ('M', 1), ('D', 2)], &mut duration)?;
+        if let Some(time) = time {
+            matched += parse_section(time, &[('H', 3), ('M', 4), ('S', 5)], &mut duration)?;
+        }
+        // Reject an empty duration such as `P` or `PT`, which carry no
+        // components and are not valid ISO 8601.
+        if matched == 0 {
+            return Err(InvalidDuration);
+        }
+        Ok(duration)
+    }
+}
+
+/// Parses one section (date or time) of an ISO 8601 duration, assigning each
+/// `value<unit>` pair to the matching field. `units` lists the permitted
+/// designators in order alongside the [`Duration`] field index they set.
+fn parse_section(
+    section: &str,
+    units: &[(char, usize)],
+    duration: &mut Duration,
+) -> Result<usize, InvalidDuration> {
+    let mut values = [None::<u64>; 6];
+    let mut number = alloc::string::String::new();
+    let mut next_unit = 0usize;
+    let mut matched = 0usize;
+    for ch in section.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+        let (_, field) = units
+            .iter()
+            .skip(next_unit)
+            .enumerate()
+            .find(|(_, (unit, _))| *unit == ch)
+            .map(|(offset, entry)| {
+                next_unit += offset + 1;
+                *entry
+            })
+            .ok_or(InvalidDuration)?;
+        let value: u64 = number.parse().map_err(|_| InvalidDuration)?;
+        number.clear();
+        values[field] = Some(value);
+        matched += 1;
+    }
+    if !number.is_empty() {
+        return Err(InvalidDuration);
+    }
+    let fields = [
+        &mut duration.years,
+        &mut duration.months,
+        &mut duration.days,
+        &mut duration.hours,
+        &mut duration.minutes,
+        &mut duration.seconds,
+    ];
+    for (slot, value) in fields.into_iter().zip(values) {
+        if let Some(value) = value {
+            *slot = value;
+        }
+    }
+    Ok(matched)
+}
+
+/// Error returned when a string is not a valid ISO 8601 duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDuration;
+
+impl core::fmt::Display for InvalidDuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("not a valid ISO 8601 duration")
+    }
+}
+
+impl crate::enc::Encode for Duration {
+    fn encode_with_tag<E: crate::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+    ) -> Result<(), E::Error> {
+        use alloc::string::ToString;
+        encoder
+            .encode_octet_string(tag, self.to_string().as_bytes())
+            .map(drop)
+    }
+}
+
+impl crate::de::Decode for Duration {
+    fn decode_with_tag<D: crate::de::Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        use crate::de::Error;
+        let bytes = decoder.decode_octet_string(tag)?;
+        let text = core::str::from_utf8(&bytes).map_err(D::Error::custom)?;
+        text.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[test]
+    fn parses_all_components() {
+        let duration = Duration::from_str("P1Y2M3DT4H5M6S").unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                years: 1,
+                months: 2,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_round_trips_through_pt0s() {
+        let zero = Duration::default();
+        assert_eq!(alloc::string::ToString::to_string(&zero), "PT0S");
+        assert_eq!(Duration::from_str("PT0S").unwrap(), zero);
+    }
+
+    #[test]
+    fn rejects_empty_and_out_of_order() {
+        assert!(Duration::from_str("P").is_err());
+        assert!(Duration::from_str("PT").is_err());
+        // `M` (months) after `D` (days) is out of canonical order.
+        assert!(Duration::from_str("P3D1M").is_err());
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(Duration::from_str("P99999999999999999999Y").is_err());
+    }
+}
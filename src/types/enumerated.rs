@@ -0,0 +1,87 @@
+//! # Enumerated
+//! The ASN.1 `ENUMERATED` type. Unlike `INTEGER`, an enumerated value carries
+//! its own universal tag and may only hold one of its defined enumerators.
+
+use super::{AsnType, Tag};
+
+/// A fieldless enum whose variants map to distinct `ENUMERATED` values.
+///
+/// `#[derive(AsnType)]` implements this for a unit-variant enum that opts into
+/// enumerated semantics, wiring each variant to its discriminant and back.
+pub trait Enumerated: Sized + Copy + 'static {
+    /// Every defined enumerator, in declaration order.
+    const VARIANTS: &'static [Self];
+
+    /// Returns the integer value of this enumerator.
+    fn discriminant(self) -> isize;
+
+    /// Returns the enumerator defined for `value`, or `None` when no variant
+    /// uses that discriminant.
+    fn from_discriminant(value: isize) -> Option<Self>;
+}
+
+/// Wraps an [`Enumerated`] value so that it encodes and decodes under
+/// [`Tag::ENUMERATED`] rather than [`Tag::INTEGER`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Enumerable<E>(pub E);
+
+impl<E: Enumerated> Enumerable<E> {
+    /// Wraps an enumerator.
+    pub fn new(value: E) -> Self {
+        Self(value)
+    }
+
+    /// Returns the discriminant that encodes this value.
+    pub fn discriminant(self) -> isize {
+        self.0.discriminant()
+    }
+
+    /// Builds a value from a decoded discriminant, rejecting any integer that
+    /// does not correspond to a defined enumerator.
+    pub fn from_discriminant(value: isize) -> Option<Self> {
+        E::from_discriminant(value).map(Self)
+    }
+
+    /// Returns the wrapped enumerator.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: Enumerated> AsnType for Enumerable<E> {
+    const TAG: Tag = Tag::ENUMERATED;
+}
+
+impl<E: Enumerated> crate::enc::Encode for Enumerable<E> {
+    fn encode_with_tag<EN: crate::enc::Encoder>(
+        &self,
+        encoder: &mut EN,
+        tag: Tag,
+    ) -> Result<(), EN::Error> {
+        // The contents are those of an `INTEGER`; only the tag distinguishes
+        // an `ENUMERATED`.
+        encoder
+            .encode_integer(tag, &num_bigint::BigInt::from(self.discriminant()))
+            .map(drop)
+    }
+}
+
+impl<E: Enumerated> crate::de::Decode for Enumerable<E> {
+    fn decode_with_tag<D: crate::de::Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        use crate::de::Error;
+        let value = decoder.decode_integer(tag)?;
+        let discriminant = value
+            .to_string()
+            .parse::<isize>()
+            .map_err(|_| D::Error::custom("enumerated value is out of range"))?;
+        Self::from_discriminant(discriminant).ok_or_else(|| {
+            D::Error::custom(alloc::format!(
+                "{} does not correspond to a defined enumerator",
+                discriminant
+            ))
+        })
+    }
+}
@@ -0,0 +1,384 @@
+//! # Real
+//! Representation and X.690 §8.5 encoding of the ASN.1 `REAL` type.
+
+use super::Tag;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// The base used by a binary `REAL` encoding (X.690 §8.5.7.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Base {
+    Two,
+    Eight,
+    Sixteen,
+}
+
+/// An ASN.1 `REAL` value.
+///
+/// Most values are carried in a native [`f64`]; the [`Real::Big`] variant
+/// keeps an arbitrary-precision mantissa and exponent so that values outside
+/// the range of a double (or with more mantissa bits than a double can hold)
+/// still round-trip through a binary encoding.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Real {
+    /// A value that fits in a native double.
+    Double(f64),
+    /// A binary value `(-1)^sign · mantissa · 2^exponent` with an
+    /// arbitrary-precision mantissa.
+    Big {
+        /// `true` for a negative value.
+        sign: bool,
+        /// The unsigned mantissa `N`.
+        mantissa: BigUint,
+        /// The base-2 exponent.
+        exponent: BigInt,
+    },
+}
+
+impl Real {
+    /// Returns the value as an [`f64`], which may lose precision for a
+    /// [`Real::Big`] value.
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Real::Double(value) => *value,
+            Real::Big {
+                sign,
+                mantissa,
+                exponent,
+            } => {
+                let mantissa: f64 = mantissa
+                    .to_string()
+                    .parse()
+                    .unwrap_or(f64::INFINITY);
+                let exponent: f64 = exponent.to_string().parse().unwrap_or(0.0);
+                let magnitude = mantissa * exponent.exp2();
+                if *sign {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+        }
+    }
+
+    /// Encodes the value into the contents octets of a `REAL` as described in
+    /// X.690 §8.5. A zero encodes as empty contents; the special values and
+    /// minus-zero each use a single identification octet.
+    pub fn to_ber_bytes(&self) -> alloc::vec::Vec<u8> {
+        // Dispatch the special forms on the variant itself rather than a lossy
+        // `to_f64()` — a `Real::Big` is always finite, so only a
+        // `Real::Double` can carry minus-zero, NaN or an infinity.
+        match self {
+            Real::Double(value) => {
+                if *value == 0.0 {
+                    // Distinguish minus-zero from plus-zero (§8.5.3, §8.5.9).
+                    return if value.is_sign_negative() {
+                        alloc::vec![0x43]
+                    } else {
+                        alloc::vec::Vec::new()
+                    };
+                }
+                if value.is_nan() {
+                    return alloc::vec![0x42];
+                }
+                if value.is_infinite() {
+                    return alloc::vec![if value.is_sign_positive() { 0x40 } else { 0x41 }];
+                }
+            }
+            Real::Big { mantissa, .. } => {
+                // Guard a degenerate zero mantissa; otherwise fall through to
+                // the binary encoding below without ever touching `to_f64()`.
+                if *mantissa == BigUint::from(0u8) {
+                    return alloc::vec::Vec::new();
+                }
+            }
+        }
+
+        let (sign, mut mantissa, mut exponent) = self.normalized_binary();
+
+        // Normalise by removing trailing zero bits from the mantissa,
+        // folding them into the exponent so encodings compare canonically
+        // (§8.5.7.5, §11.3.1).
+        while &mantissa % 2u8 == BigUint::from(0u8) {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        let exponent_octets = twos_complement(&exponent);
+        let (exponent_format, mut contents) = match exponent_octets.len() {
+            1 => (0b00, exponent_octets),
+            2 => (0b01, exponent_octets),
+            3 => (0b10, exponent_octets),
+            len => {
+                let mut octets = alloc::vec![len as u8];
+                octets.extend_from_slice(&exponent_octets);
+                (0b11, octets)
+            }
+        };
+
+        // Base 2, scaling factor F = 0 (§8.5.7.3, §8.5.7.4).
+        let first = 0b1000_0000 | (u8::from(sign) << 6) | exponent_format;
+        let mut bytes = alloc::vec![first];
+        bytes.append(&mut contents);
+        let (_, mantissa_bytes) = BigInt::from(mantissa).to_bytes_be();
+        bytes.extend_from_slice(&mantissa_bytes);
+        bytes
+    }
+
+    /// Decodes the contents octets of a `REAL` (X.690 §8.5), returning `None`
+    /// when the octets are not a well-formed encoding.
+    pub fn from_ber_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes.first() {
+            None => Some(Real::Double(0.0)),
+            Some(0x40) => Some(Real::Double(f64::INFINITY)),
+            Some(0x41) => Some(Real::Double(f64::NEG_INFINITY)),
+            Some(0x42) => Some(Real::Double(f64::NAN)),
+            Some(0x43) => Some(Real::Double(-0.0)),
+            Some(first) if first & 0b1000_0000 != 0 => Self::from_binary(*first, &bytes[1..]),
+            Some(first) => Self::from_decimal(*first, &bytes[1..]),
+        }
+    }
+
+    /// Decomposes the value into `(sign, mantissa, exponent)` for a base-2
+    /// encoding.
+    fn normalized_binary(&self) -> (bool, BigUint, BigInt) {
+        match self {
+            Real::Big {
+                sign,
+                mantissa,
+                exponent,
+            } => (*sign, mantissa.clone(), exponent.clone()),
+            Real::Double(value) => {
+                let bits = value.to_bits();
+                let sign = bits >> 63 == 1;
+                let raw_exponent = ((bits >> 52) & 0x7ff) as i64;
+                let raw_mantissa = bits & 0x000f_ffff_ffff_ffff;
+                let (mantissa, exponent) = if raw_exponent == 0 {
+                    // Subnormal: no implicit leading bit.
+                    (raw_mantissa, -1074)
+                } else {
+                    (raw_mantissa | 0x0010_0000_0000_0000, raw_exponent - 1075)
+                };
+                (sign, BigUint::from(mantissa), BigInt::from(exponent))
+            }
+        }
+    }
+
+    fn from_binary(first: u8, rest: &[u8]) -> Option<Self> {
+        let sign = first & 0b0100_0000 != 0;
+        let base = match (first >> 4) & 0b11 {
+            0b00 => Base::Two,
+            0b01 => Base::Eight,
+            0b10 => Base::Sixteen,
+            _ => return None,
+        };
+        let scaling = u32::from((first >> 2) & 0b11);
+        let (exponent_len, exponent_start) = match first & 0b11 {
+            0b00 => (1usize, 0usize),
+            0b01 => (2, 0),
+            0b10 => (3, 0),
+            _ => (*rest.first()? as usize, 1),
+        };
+        let exponent_end = exponent_start.checked_add(exponent_len)?;
+        let exponent_octets = rest.get(exponent_start..exponent_end)?;
+        let exponent = BigInt::from_signed_bytes_be(exponent_octets);
+        let mantissa = BigUint::from_bytes_be(rest.get(exponent_end..)?);
+
+        let base_log2 = match base {
+            Base::Two => 1,
+            Base::Eight => 3,
+            Base::Sixteen => 4,
+        };
+        let exponent = exponent * base_log2 + BigInt::from(scaling);
+
+        let mut mantissa = mantissa;
+        let mut exponent = exponent;
+        while mantissa != BigUint::from(0u8) && &mantissa % 2u8 == BigUint::from(0u8) {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+        Some(Real::Big {
+            sign,
+            mantissa,
+            exponent,
+        })
+    }
+
+    /// Decodes a decimal (character) encoding (§8.5.8). Bits 2–1 of the
+    /// identification octet select the ISO 6093 textual form, which we
+    /// validate before parsing so that an NR form and its contents agree.
+    ///
+    /// The encoder only ever emits the binary form (as canonical DER requires
+    /// of a `REAL`); this path exists so a BER decoder can still accept a
+    /// decimal value produced elsewhere.
+    fn from_decimal(first: u8, rest: &[u8]) -> Option<Self> {
+        let text = core::str::from_utf8(rest).ok()?.trim();
+        if text.is_empty() {
+            return None;
+        }
+        let has_point = text.contains('.') || text.contains(',');
+        let has_exponent = text.contains('E') || text.contains('e');
+        match first & 0b11 {
+            // NR1: integer, no decimal mark and no exponent.
+            0b01 if has_point || has_exponent => return None,
+            // NR2: decimal mark, no exponent.
+            0b10 if !has_point || has_exponent => return None,
+            // NR3: exponent required.
+            0b11 if !has_exponent => return None,
+            0b01 | 0b10 | 0b11 => {}
+            _ => return None,
+        }
+        // ISO 6093 permits a comma as the decimal mark; Rust's parser wants a
+        // period.
+        let normalized = text.replace(',', ".");
+        normalized.parse().ok().map(Real::Double)
+    }
+}
+
+impl From<f64> for Real {
+    fn from(value: f64) -> Self {
+        Real::Double(value)
+    }
+}
+
+impl From<f32> for Real {
+    fn from(value: f32) -> Self {
+        Real::Double(value.into())
+    }
+}
+
+impl crate::enc::Encode for Real {
+    fn encode_with_tag<E: crate::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+    ) -> Result<(), E::Error> {
+        // The contents octets are the X.690 §8.5 encoding of the value.
+        encoder.encode_octet_string(tag, &self.to_ber_bytes()).map(drop)
+    }
+}
+
+impl crate::de::Decode for Real {
+    fn decode_with_tag<D: crate::de::Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        use crate::de::Error;
+        let bytes = decoder.decode_octet_string(tag)?;
+        Self::from_ber_bytes(&bytes)
+            .ok_or_else(|| D::Error::custom("not a well-formed REAL encoding"))
+    }
+}
+
+/// `f32`/`f64` delegate through [`Real`] so that a native float field encodes
+/// and decodes with the full §8.5 algorithm.
+macro_rules! float_codec {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl crate::enc::Encode for $ty {
+                fn encode_with_tag<E: crate::enc::Encoder>(
+                    &self,
+                    encoder: &mut E,
+                    tag: Tag,
+                ) -> Result<(), E::Error> {
+                    crate::enc::Encode::encode_with_tag(&Real::from(*self), encoder, tag)
+                }
+            }
+
+            impl crate::de::Decode for $ty {
+                fn decode_with_tag<D: crate::de::Decoder>(
+                    decoder: &mut D,
+                    tag: Tag,
+                ) -> Result<Self, D::Error> {
+                    <Real as crate::de::Decode>::decode_with_tag(decoder, tag)
+                        .map(|real| real.to_f64() as $ty)
+                }
+            }
+        )+
+    };
+}
+
+float_codec!(f32, f64);
+
+/// Encodes `value` as a minimal two's-complement big-endian integer.
+fn twos_complement(value: &BigInt) -> alloc::vec::Vec<u8> {
+    if value.sign() == Sign::NoSign {
+        return alloc::vec![0];
+    }
+    value.to_signed_bytes_be()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(Real::Double(0.0).to_ber_bytes(), alloc::vec::Vec::<u8>::new());
+        assert_eq!(Real::Double(-0.0).to_ber_bytes(), alloc::vec![0x43]);
+        assert_eq!(Real::Double(f64::INFINITY).to_ber_bytes(), alloc::vec![0x40]);
+        assert_eq!(Real::Double(f64::NEG_INFINITY).to_ber_bytes(), alloc::vec![0x41]);
+        assert_eq!(Real::Double(f64::NAN).to_ber_bytes(), alloc::vec![0x42]);
+
+        assert!(Real::from_ber_bytes(&[]).unwrap().to_f64() == 0.0);
+        assert!(Real::from_ber_bytes(&[0x42]).unwrap().to_f64().is_nan());
+        assert_eq!(Real::from_ber_bytes(&[0x40]).unwrap().to_f64(), f64::INFINITY);
+    }
+
+    /// Encoding is canonical, so a decode/re-encode is a fixed point.
+    fn assert_canonical(value: Real) {
+        let bytes = value.to_ber_bytes();
+        let decoded = Real::from_ber_bytes(&bytes).expect("valid encoding");
+        assert_eq!(decoded.to_ber_bytes(), bytes);
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        for value in [1.0, -1.0, -2.5, 0.125, 3.141_592_653_589_793, 1e300] {
+            assert_canonical(Real::Double(value));
+            assert_eq!(
+                Real::from_ber_bytes(&Real::Double(value).to_ber_bytes())
+                    .unwrap()
+                    .to_f64(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn subnormal_round_trip() {
+        let subnormal = f64::from_bits(1);
+        assert_eq!(
+            Real::from_ber_bytes(&Real::Double(subnormal).to_ber_bytes())
+                .unwrap()
+                .to_f64(),
+            subnormal
+        );
+    }
+
+    #[test]
+    fn big_out_of_f64_range_is_finite() {
+        // 10^400 overflows f64; the encoder must not mistake it for infinity.
+        let mantissa = num_bigint::BigUint::from(10u8).pow(400);
+        let value = Real::Big {
+            sign: false,
+            mantissa,
+            exponent: BigInt::from(0),
+        };
+        let bytes = value.to_ber_bytes();
+        assert_ne!(bytes, alloc::vec![0x40]);
+        assert_ne!(bytes, alloc::vec::Vec::<u8>::new());
+        assert!(bytes[0] & 0b1000_0000 != 0, "expected a binary encoding");
+        assert_canonical(value);
+    }
+
+    #[test]
+    fn decimal_nr_forms() {
+        // NR3 (bits 2-1 = 11) with an exponent.
+        assert_eq!(
+            Real::from_ber_bytes(b"\x033.14E0").unwrap().to_f64(),
+            3.14
+        );
+        // An NR1 identification octet rejects contents carrying an exponent.
+        assert!(Real::from_ber_bytes(b"\x01314E-2").is_none());
+    }
+}
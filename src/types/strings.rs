@@ -0,0 +1,206 @@
+//! # Restricted character strings
+//! Newtypes over [`Bytes`] that validate their permitted alphabet on
+//! construction, so an illegal character is rejected rather than silently
+//! encoded into a string whose tag promises a restricted character set.
+
+use super::Tag;
+use bytes::Bytes;
+
+/// Error returned when a byte sequence contains a character that is not
+/// permitted by a restricted string's alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidRestrictedString {
+    /// The byte that violated the permitted alphabet.
+    pub byte: u8,
+    /// The name of the ASN.1 type the byte was rejected for.
+    pub expected: &'static str,
+}
+
+impl core::fmt::Display for InvalidRestrictedString {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "byte {:#04x} is not permitted in a {}",
+            self.byte, self.expected
+        )
+    }
+}
+
+macro_rules! restricted_string {
+    ($(#[$meta:meta])* $name:ident, $expected:literal, $permits:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(Bytes);
+
+        impl $name {
+            /// Constructs the string, validating that every byte is permitted
+            /// by the type's alphabet.
+            pub fn new(bytes: impl Into<Bytes>) -> Result<Self, InvalidRestrictedString> {
+                let bytes = bytes.into();
+                let permits: fn(u8) -> bool = $permits;
+                if let Some(&byte) = bytes.iter().find(|&&byte| !permits(byte)) {
+                    return Err(InvalidRestrictedString {
+                        byte,
+                        expected: $expected,
+                    });
+                }
+                Ok(Self(bytes))
+            }
+
+            /// Returns the validated contents as a byte slice.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl core::convert::TryFrom<&'_ str> for $name {
+            type Error = InvalidRestrictedString;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::new(Bytes::copy_from_slice(value.as_bytes()))
+            }
+        }
+
+        impl core::convert::TryFrom<alloc::string::String> for $name {
+            type Error = InvalidRestrictedString;
+
+            fn try_from(value: alloc::string::String) -> Result<Self, Self::Error> {
+                Self::new(Bytes::copy_from_slice(value.as_bytes()))
+            }
+        }
+
+        impl core::convert::TryFrom<alloc::vec::Vec<u8>> for $name {
+            type Error = InvalidRestrictedString;
+
+            fn try_from(value: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+restricted_string!(
+    /// An `IA5String`, restricted to the 7-bit International Alphabet No. 5.
+    IA5String,
+    "IA5String",
+    |byte| byte <= 0x7f
+);
+
+restricted_string!(
+    /// A `PrintableString`, restricted to `A–Z a–z 0–9` and the symbols
+    /// `(space) ' ( ) + , - . / : = ?`.
+    PrintableString,
+    "PrintableString",
+    |byte| matches!(
+        byte,
+        b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b' '
+            | b'\''
+            | b'('
+            | b')'
+            | b'+'
+            | b','
+            | b'-'
+            | b'.'
+            | b'/'
+            | b':'
+            | b'='
+            | b'?'
+    )
+);
+
+restricted_string!(
+    /// A `VisibleString` (ISO 646), restricted to the printable ASCII range.
+    VisibleString,
+    "VisibleString",
+    |byte| (0x20..=0x7e).contains(&byte)
+);
+
+restricted_string!(
+    /// A `NumericString`, restricted to the digits `0–9` and space.
+    NumericString,
+    "NumericString",
+    |byte| matches!(byte, b'0'..=b'9' | b' ')
+);
+
+/// Implements the BER/DER codec for a restricted string: the contents are the
+/// validated octets, and decoding re-runs the alphabet check so malformed
+/// input is rejected rather than producing an invalid value.
+macro_rules! restricted_string_codec {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            impl crate::enc::Encode for $name {
+                fn encode_with_tag<E: crate::enc::Encoder>(
+                    &self,
+                    encoder: &mut E,
+                    tag: Tag,
+                ) -> Result<(), E::Error> {
+                    encoder.encode_octet_string(tag, self.as_bytes()).map(drop)
+                }
+            }
+
+            impl crate::de::Decode for $name {
+                fn decode_with_tag<D: crate::de::Decoder>(
+                    decoder: &mut D,
+                    tag: Tag,
+                ) -> Result<Self, D::Error> {
+                    use crate::de::Error;
+                    let bytes = decoder.decode_octet_string(tag)?;
+                    Self::new(bytes).map_err(D::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+restricted_string_codec!(IA5String, PrintableString, VisibleString, NumericString);
+
+restricted_string!(
+    /// A `TeletexString` (a.k.a. `T61String`). The full T.61 alphabet is
+    /// modelled as an 8-bit character set.
+    TeletexString,
+    "TeletexString",
+    |_| true
+);
+
+/// `T61String` is the historical name for [`TeletexString`].
+pub type T61String = TeletexString;
+
+restricted_string!(
+    /// A `VideotexString`, modelled as an 8-bit character set.
+    VideotexString,
+    "VideotexString",
+    |_| true
+);
+
+restricted_string!(
+    /// A `GraphicString`, modelled as an 8-bit character set.
+    GraphicString,
+    "GraphicString",
+    |_| true
+);
+
+restricted_string!(
+    /// A `GeneralString`, modelled as an 8-bit character set.
+    GeneralString,
+    "GeneralString",
+    |_| true
+);
+
+restricted_string!(
+    /// An `ObjectDescriptor`, treated as an ordinary graphic restricted
+    /// string.
+    ObjectDescriptor,
+    "ObjectDescriptor",
+    |_| true
+);
+
+restricted_string_codec!(
+    TeletexString,
+    VideotexString,
+    GraphicString,
+    GeneralString,
+    ObjectDescriptor,
+);